@@ -0,0 +1,120 @@
+//! Batched vector-matrix multiplication using the Method of Four Russians.
+//!
+//! Applications that encode or decode streams of codewords pay for a popcount per
+//! matrix row on every call to [`matrix_mul`](crate::matrix_mul). [`matrix_mul_batch`]
+//! instead partitions the matrix's columns into small blocks and precomputes, per
+//! block, the contribution of every possible bit pattern in that block. Multiplying a
+//! vector then costs one table lookup and XOR per block, rather than one popcount per
+//! row.
+
+use std::mem::size_of;
+
+use num_traits::PrimInt;
+
+use crate::matrix_mul;
+
+/// Below this many input vectors, the cost of building the Four Russians tables isn't
+/// worth it, so [`matrix_mul_batch`] falls back to [`matrix_mul`] per vector.
+const MIN_BATCH: usize = 32;
+
+/// Width in bits of each column block. `2`<sup>`BLOCK_BITS`</sup> entries are
+/// precomputed per block.
+const BLOCK_BITS: usize = 8;
+
+/// Precomputed Four Russians tables for a fixed matrix, letting
+/// **vM**<sup>T</sup> be computed as one lookup and XOR per
+/// [`BLOCK_BITS`](BLOCK_BITS)-wide block of `v`, instead of one popcount per matrix row.
+struct FourRussians<O> {
+    /// `tables[b][x]` is the contribution to **vM**<sup>T</sup> of the bit pattern `x`
+    /// occupying block `b` of the input vector, with every other bit zero.
+    tables: Vec<Vec<O>>,
+}
+
+impl<O> FourRussians<O> where
+    O: PrimInt + From<u8>,
+{
+    /// Precompute the block tables for the given matrix and input word width.
+    fn new<I>(mat: &[I], bits: usize) -> Self where
+        I: PrimInt,
+    {
+        let blocks = bits.div_ceil(BLOCK_BITS);
+
+        let tables = (0..blocks).map(|b| {
+            let shift = b * BLOCK_BITS;
+
+            (0..1usize << BLOCK_BITS).map(|x| {
+                let chunk = I::from(x).unwrap() << shift;
+                matrix_mul(chunk, mat)
+            }).collect()
+        }).collect();
+
+        FourRussians { tables }
+    }
+
+    /// Compute **vM**<sup>T</sup> for the given word by combining its block
+    /// contributions.
+    fn mul<I>(&self, word: I) -> O where
+        I: PrimInt,
+    {
+        let mask = I::from((1usize << BLOCK_BITS) - 1).unwrap();
+
+        self.tables.iter().enumerate().fold(O::zero(), |accum, (b, table)| {
+            let shift = b * BLOCK_BITS;
+            let chunk = ((word >> shift) & mask).to_usize().unwrap();
+            accum ^ table[chunk]
+        })
+    }
+}
+
+/// Compute **vM**<sup>T</sup> for every word in `words`, reusing a single set of
+/// precomputed Four Russians tables across the whole batch.
+///
+/// Falls back to calling [`matrix_mul`] on each word directly when `words` is smaller
+/// than [`MIN_BATCH`], since the table precomputation wouldn't pay for itself.
+pub fn matrix_mul_batch<I, O>(words: &[I], mat: &[I]) -> Vec<O> where
+    I: PrimInt,
+    O: PrimInt + From<u8>,
+{
+    if words.len() < MIN_BATCH {
+        return words.iter().map(|&word| matrix_mul(word, mat)).collect();
+    }
+
+    let bits = size_of::<I>() * 8;
+    let table = FourRussians::new(mat, bits);
+
+    words.iter().map(|&word| table.mul(word)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAT: [u32; 6] = [
+        0b1111,
+        0b0010,
+        0b1000,
+        0b0101,
+        0b0010,
+        0b1010,
+    ];
+
+    #[test]
+    fn test_batch_matches_single_small() {
+        let words: Vec<u32> = vec![0b1010, 0b0110, 0b1111, 0b0000];
+
+        let batch: Vec<u32> = matrix_mul_batch(&words, &MAT);
+        let single: Vec<u32> = words.iter().map(|&w| matrix_mul(w, &MAT)).collect();
+
+        assert_eq!(batch, single);
+    }
+
+    #[test]
+    fn test_batch_matches_single_large() {
+        let words: Vec<u32> = (0..200).collect();
+
+        let batch: Vec<u32> = matrix_mul_batch(&words, &MAT);
+        let single: Vec<u32> = words.iter().map(|&w| matrix_mul(w, &MAT)).collect();
+
+        assert_eq!(batch, single);
+    }
+}
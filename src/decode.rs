@@ -0,0 +1,197 @@
+//! Syndrome-based decoding of received words against a parity-check matrix.
+//!
+//! These routines build on [`matrix_mul`](crate::matrix_mul) to go from a syndrome back
+//! to a corrected codeword, for the single-error-correcting and general
+//! multiple-error-correcting cases.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem::size_of;
+
+use num_traits::PrimInt;
+
+use crate::matrix_mul;
+
+/// The result of decoding a received word against a parity-check matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Decoded<I> {
+    /// The word after any detected error has been corrected.
+    pub word: I,
+    /// True if an error was detected but couldn't be corrected.
+    pub uncorrectable: bool,
+}
+
+/// Decode `word` against the given parity-check matrix `mat`, assuming a
+/// single-error-correcting code.
+///
+/// The syndrome **s** = **vM**<sup>T</sup> is computed with [`matrix_mul`]. If **s** is
+/// zero, `word` is returned unchanged. Otherwise, each column of `mat` — the word formed
+/// by bit `j` of every row — is compared against **s**; a matching column identifies the
+/// bit to flip. If no column matches, the error is detected but can't be corrected.
+pub fn decode_single<I, O>(word: I, mat: &[I]) -> Decoded<I> where
+    I: PrimInt,
+    O: PrimInt + From<u8>,
+{
+    let syndrome: O = matrix_mul(word, mat);
+
+    if syndrome.is_zero() {
+        return Decoded { word, uncorrectable: false };
+    }
+
+    for j in 0..size_of::<I>() * 8 {
+        if column::<I, O>(mat, j) == syndrome {
+            return Decoded { word: word ^ (I::one() << j), uncorrectable: false };
+        }
+    }
+
+    Decoded { word, uncorrectable: true }
+}
+
+/// Compute the word formed by taking bit `j` of every row of `mat`, in the same
+/// bit order that [`matrix_mul`] assembles its output.
+fn column<I, O>(mat: &[I], j: usize) -> O where
+    I: PrimInt,
+    O: PrimInt + From<u8>,
+{
+    mat.iter().fold(O::zero(), |accum, &row| {
+        let bit = ((row >> j) & I::one()) == I::one();
+        accum << 1 | (bit as u8).into()
+    })
+}
+
+/// A precomputed table mapping syndromes to their minimum-weight error pattern, for
+/// decoding codes that correct more than one error.
+///
+/// The table is built once from the parity-check matrix and the maximum number of
+/// errors to correct, then decoding is an O(1) lookup.
+pub struct SyndromeTable<I, O> {
+    leaders: HashMap<O, I>,
+}
+
+impl<I, O> SyndromeTable<I, O> where
+    I: PrimInt,
+    O: PrimInt + From<u8> + Hash + Eq,
+{
+    /// Build a syndrome table for `mat`, enumerating all error patterns of weight up to
+    /// `max_errors` and recording the minimum-weight coset leader for each syndrome.
+    ///
+    /// Construction cost is `O(Σ C(bits, weight))` for `weight` in `0..=max_errors`,
+    /// where `bits` is the width of `I` — it grows combinatorially, so keep `max_errors`
+    /// to the 2-3 errors typical of a BCH code rather than letting a caller pass a large
+    /// bound (e.g. `C(64, 5)` is already ~7.6M error patterns for a 64-bit word).
+    pub fn new(mat: &[I], max_errors: usize) -> Self {
+        let bits = size_of::<I>() * 8;
+        let mut leaders = HashMap::new();
+
+        for weight in 0..=max_errors {
+            for pattern in combinations(bits, weight) {
+                let err = pattern.iter().fold(I::zero(), |accum, &j| {
+                    accum | (I::one() << j)
+                });
+                let syndrome: O = matrix_mul(err, mat);
+
+                leaders.entry(syndrome).or_insert(err);
+            }
+        }
+
+        SyndromeTable { leaders }
+    }
+
+    /// Decode `word` by computing its syndrome and correcting the associated
+    /// minimum-weight error pattern, if any is known.
+    pub fn decode(&self, word: I, mat: &[I]) -> Decoded<I> {
+        let syndrome: O = matrix_mul(word, mat);
+
+        match self.leaders.get(&syndrome) {
+            Some(&err) => Decoded { word: word ^ err, uncorrectable: false },
+            None => Decoded { word, uncorrectable: !syndrome.is_zero() },
+        }
+    }
+}
+
+/// Enumerate all `weight`-sized subsets of `0..bits`, as ascending lists of bit
+/// positions.
+fn combinations(bits: usize, weight: usize) -> Vec<Vec<usize>> {
+    if weight == 0 {
+        return vec![vec![]];
+    }
+
+    if weight > bits {
+        return vec![];
+    }
+
+    let mut out = vec![];
+
+    for first in 0..bits {
+        for mut rest in combinations(bits - first - 1, weight - 1) {
+            for pos in rest.iter_mut() {
+                *pos += first + 1;
+            }
+
+            rest.insert(0, first);
+            out.push(rest);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_single_no_error() {
+        // 4 rows over all 8 bits of `I`, with every one of the 8 columns distinct and
+        // nonzero, so every single-bit error is both detectable and correctable.
+        let mat = [
+            0b10000000u8,
+            0b01111000,
+            0b01100110,
+            0b01010101,
+        ];
+
+        let d: Decoded<u8> = decode_single::<u8, u16>(0b00000111, &mat);
+        assert_eq!(d.word, 0b00000111);
+        assert!(!d.uncorrectable);
+    }
+
+    #[test]
+    fn test_decode_single_corrects_one_bit() {
+        let mat = [
+            0b10000000u8,
+            0b01111000,
+            0b01100110,
+            0b01010101,
+        ];
+
+        let correct = 0b00000111u8;
+
+        for j in 0..8 {
+            let received = correct ^ (1 << j);
+            let d: Decoded<u8> = decode_single::<u8, u16>(received, &mat);
+            assert_eq!(d.word, correct);
+            assert!(!d.uncorrectable);
+        }
+    }
+
+    #[test]
+    fn test_syndrome_table_matches_single_error_decode() {
+        let mat = [
+            0b10000000u8,
+            0b01111000,
+            0b01100110,
+            0b01010101,
+        ];
+
+        let table: SyndromeTable<u8, u16> = SyndromeTable::new(&mat, 1);
+        let correct = 0b00000111u8;
+
+        assert_eq!(table.decode(correct, &mat).word, correct);
+
+        for j in 0..8 {
+            let received = correct ^ (1 << j);
+            assert_eq!(table.decode(received, &mat).word, correct);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Generator-matrix construction from a generator polynomial.
+//!
+//! Cyclic and BCH codes (like the DMR/P25 (17,9,5) family) are usually specified by a
+//! generator polynomial `g(x)` rather than a hand-written matrix. [`generator_matrix`]
+//! derives the systematic parity rows consumed by
+//! [`matrix_mul_systematic`](crate::matrix_mul_systematic) directly from `g(x)` and the
+//! codeword length `n`, by polynomial long division over GF(2).
+
+use num_traits::PrimInt;
+use std::mem::size_of;
+
+/// Compute the degree of the given polynomial, i.e. the bit position of its highest set
+/// bit.
+fn degree<I: PrimInt>(poly: I) -> usize {
+    size_of::<I>() * 8 - 1 - poly.leading_zeros() as usize
+}
+
+/// Compute `x`<sup>`e`</sup> mod `poly`, where `poly` has the given degree, by repeated
+/// shift-and-XOR over GF(2).
+fn remainder<I: PrimInt>(e: usize, poly: I, deg: usize) -> I {
+    let mut val = I::one() << e;
+
+    for b in (deg..=e).rev() {
+        if (val >> b) & I::one() == I::one() {
+            val = val ^ (poly << (b - deg));
+        }
+    }
+
+    val
+}
+
+/// Derive the systematic generator/parity matrix for the cyclic code with generator
+/// polynomial `poly` and codeword length `n`.
+///
+/// For each of the `k = n - deg(poly)` message bit positions, this computes
+/// `x`<sup>`deg(poly) + i`</sup> mod `poly` — the contribution of that message bit to the
+/// parity bits under polynomial division — and assembles those remainders into the `deg(poly)`
+/// matrix rows that [`matrix_mul_systematic`](crate::matrix_mul_systematic) consumes, most
+/// significant parity bit first.
+///
+/// ```rust
+/// use binfield_matrix::{generator::generator_matrix, matrix_mul_systematic};
+///
+/// // g(x) = x^8 + x^5 + x^4 + x^3 + 1, for the (17, 9) code.
+/// let mat = generator_matrix::<u32>(0b100111001, 17);
+/// assert_eq!(mat.len(), 8);
+///
+/// // Message 1 encodes to the generator polynomial itself.
+/// let w: u32 = matrix_mul_systematic(1u32, &mat);
+/// assert_eq!(w, 0b100111001);
+/// ```
+pub fn generator_matrix<I>(poly: I, n: usize) -> Vec<I> where
+    I: PrimInt,
+{
+    let deg = degree(poly);
+    assert!(n > deg, "code length {} must be greater than deg(poly) = {}", n, deg);
+    let k = n - deg;
+
+    let remainders: Vec<I> = (0..k).map(|i| remainder(deg + i, poly, deg)).collect();
+
+    (0..deg).rev().map(|j| {
+        remainders.iter().enumerate().fold(I::zero(), |row, (i, &r)| {
+            row | (((r >> j) & I::one()) << i)
+        })
+    }).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix_mul_systematic;
+
+    #[test]
+    fn test_generator_matrix() {
+        let mat = generator_matrix::<u32>(0b100111001, 17);
+
+        assert_eq!(mat, vec![
+            0b100111100,
+            0b010011110,
+            0b001001111,
+            0b100011011,
+            0b110110001,
+            0b111100100,
+            0b011110010,
+            0b001111001,
+        ]);
+    }
+
+    #[test]
+    fn test_encodes_via_systematic_mul() {
+        let mat = generator_matrix::<u32>(0b100111001, 17);
+
+        let w: u32 = matrix_mul_systematic(0u32, &mat);
+        assert_eq!(w, 0);
+
+        let w: u32 = matrix_mul_systematic(1u32, &mat);
+        assert_eq!(w, 0b100111001);
+
+        let w: u32 = matrix_mul_systematic(0b10u32, &mat);
+        assert_eq!(w, 0b1001110010);
+
+        let w: u32 = matrix_mul_systematic(0b111111111u32, &mat);
+        assert_eq!(w, 0b11111111111111111);
+    }
+}
@@ -47,6 +47,13 @@
 
 extern crate num_traits;
 
+pub mod batch;
+pub mod decode;
+pub mod generator;
+#[cfg(feature = "simd_batch")]
+pub mod simd;
+pub mod wide;
+
 use num_traits::PrimInt;
 
 /// Compute **vM**<sup>T</sup>, where **v** is the given word and **M** is the given
@@ -0,0 +1,164 @@
+//! Arbitrary-width vectors and matrices, for codes wider than a single machine word.
+//!
+//! [`matrix_mul`](crate::matrix_mul) and friends are limited to vectors and matrix
+//! columns that fit in a single [`PrimInt`](num_traits::PrimInt), so codes like a
+//! (255, *k*) BCH code can't be expressed. [`BitVec`] and [`BitMatrix`] pack bits across
+//! as many `u64` words as needed, and [`matrix_mul_wide`] reuses the same "AND rows,
+//! parity of popcount" approach across those words.
+//!
+//! Bit 0 of a [`BitVec`] is its least significant bit, and row `i` of a [`BitMatrix`]
+//! produces bit `i` of a [`matrix_mul_wide`] result.
+
+const WORD_BITS: usize = 64;
+
+fn words_for(bits: usize) -> usize {
+    bits.div_ceil(WORD_BITS)
+}
+
+/// A bit vector of arbitrary length, packed into `u64` words.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitVec {
+    bits: usize,
+    words: Vec<u64>,
+}
+
+impl BitVec {
+    /// Create a new, zeroed bit vector of the given length in bits.
+    pub fn new(bits: usize) -> Self {
+        BitVec {
+            bits,
+            words: vec![0; words_for(bits)],
+        }
+    }
+
+    /// Number of bits in the vector.
+    pub fn len(&self) -> usize { self.bits }
+
+    /// True if the vector has no bits.
+    pub fn is_empty(&self) -> bool { self.bits == 0 }
+
+    /// Get the bit at the given position.
+    pub fn get(&self, idx: usize) -> bool {
+        assert!(idx < self.bits);
+        (self.words[idx / WORD_BITS] >> (idx % WORD_BITS)) & 1 == 1
+    }
+
+    /// Set the bit at the given position to the given value.
+    pub fn set(&mut self, idx: usize, val: bool) {
+        assert!(idx < self.bits);
+
+        let mask = 1u64 << (idx % WORD_BITS);
+        let word = &mut self.words[idx / WORD_BITS];
+
+        if val {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// Count the number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    /// Compute the bitwise AND of this vector with another of the same length.
+    pub fn and(&self, other: &BitVec) -> BitVec {
+        assert_eq!(self.bits, other.bits);
+
+        BitVec {
+            bits: self.bits,
+            words: self.words.iter().zip(&other.words).map(|(a, b)| a & b).collect(),
+        }
+    }
+}
+
+/// A binary matrix of arbitrary width, stored as one [`BitVec`] row per matrix row.
+#[derive(Clone, Debug)]
+pub struct BitMatrix {
+    cols: usize,
+    rows: Vec<BitVec>,
+}
+
+impl BitMatrix {
+    /// Create a matrix from the given rows, each of which must have length `cols`.
+    pub fn new(cols: usize, rows: Vec<BitVec>) -> Self {
+        assert!(rows.iter().all(|r| r.len() == cols));
+        BitMatrix { cols, rows }
+    }
+
+    /// Number of columns in the matrix.
+    pub fn cols(&self) -> usize { self.cols }
+
+    /// Number of rows in the matrix.
+    pub fn rows(&self) -> &[BitVec] { &self.rows }
+}
+
+/// Compute **vM**<sup>T</sup>, where **v** is the given vector and **M** is the given
+/// matrix, for vectors and matrix columns wider than a single machine word.
+///
+/// This is the same "AND with each row, take the parity of the popcount, assemble the
+/// result" approach as [`matrix_mul`](crate::matrix_mul), but iterating across multiple
+/// backing words per row.
+pub fn matrix_mul_wide(word: &BitVec, mat: &BitMatrix) -> BitVec {
+    assert_eq!(word.len(), mat.cols());
+
+    let mut out = BitVec::new(mat.rows().len());
+
+    for (i, row) in mat.rows().iter().enumerate() {
+        out.set(i, word.and(row).count_ones() & 1 == 1);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn vec_from_bits(bits: &[u8]) -> BitVec {
+        let mut v = BitVec::new(bits.len());
+        for (i, &b) in bits.iter().enumerate() {
+            v.set(i, b == 1);
+        }
+        v
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut v = BitVec::new(130);
+        v.set(0, true);
+        v.set(64, true);
+        v.set(129, true);
+
+        assert!(v.get(0));
+        assert!(v.get(64));
+        assert!(v.get(129));
+        assert!(!v.get(1));
+        assert!(!v.get(63));
+        assert_eq!(v.count_ones(), 3);
+    }
+
+    #[test]
+    fn test_matrix_mul_wide() {
+        // Same matrix and vector as the word-sized doctest example, just packed wide.
+        let word = vec_from_bits(&[1, 0, 1, 0]);
+
+        let mat = BitMatrix::new(4, vec![
+            vec_from_bits(&[1, 1, 1, 1]),
+            vec_from_bits(&[0, 0, 1, 0]),
+            vec_from_bits(&[1, 0, 0, 0]),
+            vec_from_bits(&[0, 1, 0, 1]),
+            vec_from_bits(&[0, 0, 1, 0]),
+            vec_from_bits(&[1, 0, 1, 0]),
+        ]);
+
+        let out = matrix_mul_wide(&word, &mat);
+        assert_eq!(out.len(), 6);
+
+        let expected = [0, 1, 1, 0, 1, 0];
+        for (i, &bit) in expected.iter().enumerate() {
+            assert_eq!(out.get(i), bit == 1, "bit {}", i);
+        }
+    }
+}